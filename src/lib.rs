@@ -15,73 +15,268 @@
 
 use serde::{Deserialize, Serialize};
 use std::{
+    any::type_name,
     cmp::Ordering,
     convert::TryFrom,
     fmt,
     hash::{Hash, Hasher},
+    io::{Read, Write},
     marker::PhantomData,
     str::FromStr,
+    sync::atomic::{self, AtomicU8},
 };
-use uuid::Uuid;
+use uuid::{Uuid, Variant};
+
+#[cfg(feature = "debug_origin")]
+use std::panic::Location;
+
+#[cfg(feature = "chrono")]
+use uuid::{timestamp::UUID_TICKS_BETWEEN_EPOCHS, Version};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct IDDef(pub String);
 
 /// Typed Unique Identifier (uuidv4).
+///
+/// Identifier is laid out as raw 16 UUID bytes, so it can be used for FFI or memory mapping.
+/// The only exception are debug builds with `debug_origin` feature enabled, which also store
+/// creation site (see `ID::origin`) and make `ID<T>` 24 bytes wide on 64-bit targets - do not
+/// rely on its layout in such builds.
 #[derive(Serialize, Deserialize)]
 #[repr(C)]
 #[serde(try_from = "IDDef")]
 #[serde(into = "IDDef")]
 pub struct ID<T> {
     id: Uuid,
+    #[cfg(all(feature = "debug_origin", debug_assertions))]
+    #[serde(skip_serializing, skip_deserializing)]
+    origin: Option<&'static Location<'static>>,
     #[serde(skip_serializing, skip_deserializing)]
     _phantom: PhantomData<fn() -> T>,
 }
 
+// Layout is part of public contract (see `ID` docs), so it must not change unnoticed.
+#[cfg(not(all(feature = "debug_origin", debug_assertions)))]
+const _: () = assert!(std::mem::size_of::<ID<()>>() == ID::<()>::SIZE);
+#[cfg(not(all(feature = "debug_origin", debug_assertions)))]
+const _: () = assert!(std::mem::align_of::<ID<()>>() == 1);
+#[cfg(all(feature = "debug_origin", debug_assertions))]
+const _: () =
+    assert!(std::mem::size_of::<ID<()>>() == ID::<()>::SIZE + std::mem::size_of::<usize>());
+#[cfg(all(feature = "debug_origin", debug_assertions))]
+const _: () = assert!(std::mem::align_of::<ID<()>>() == std::mem::align_of::<usize>());
+
 impl<T> ID<T> {
+    /// Length of raw UUID bytes (see [`ID`] for layout exception with `debug_origin`).
+    pub const SIZE: usize = 16;
+
     /// Creates new identifier.
     #[inline]
+    #[track_caller]
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates batch of time-based (uuidv7) identifiers, strictly increasing even when
+    /// generated within the same millisecond, also against other batches and v7 UUIDs
+    /// created in this process.
+    pub fn new_batch_v7(n: usize) -> Vec<Self> {
+        (0..n)
+            .map(|_| Self {
+                id: Uuid::now_v7(),
+                #[cfg(all(feature = "debug_origin", debug_assertions))]
+                origin: None,
+                _phantom: PhantomData,
+            })
+            .collect()
+    }
+
     /// Creates new identifier from raw bytes.
     #[inline]
     pub fn from_bytes(bytes: [u8; 16]) -> Self {
         Self {
             id: Uuid::from_bytes(bytes),
+            #[cfg(all(feature = "debug_origin", debug_assertions))]
+            origin: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Creates new identifier from raw bytes, rejecting ones that do not form RFC 4122 UUID
+    /// of any known version.
+    pub fn checked_from_bytes(bytes: [u8; 16]) -> Result<Self, String> {
+        let id = Uuid::from_bytes(bytes);
+        if id.get_variant() == Variant::RFC4122 && (1..=8).contains(&id.get_version_num()) {
+            Ok(Self::from_bytes(bytes))
+        } else {
+            Err(id.to_string())
+        }
+    }
+
+    /// Reads exactly 16 bytes from reader and creates new identifier from them.
+    pub fn from_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut bytes = [0; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Writes raw 16 bytes of identifier into writer.
+    #[inline]
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(self.id.as_bytes())
+    }
+
+    /// Parses list of identifiers separated by commas, semicolons or new lines.
+    /// Empty entries are skipped. On failure returns error together with zero-based index of
+    /// invalid entry, counted among non-empty entries only.
+    pub fn parse_many(s: &str) -> Result<Vec<Self>, (usize, String)> {
+        s.split(&[',', ';', '\n'][..])
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .enumerate()
+            .map(|(index, item)| Self::from_str(item).map_err(|error| (index, error)))
+            .collect()
+    }
+
+    /// Gets displayable adapter formatting identifiers as comma separated list.
+    #[inline]
+    pub fn display_list(ids: &[Self]) -> impl fmt::Display + '_ {
+        DisplayList(ids)
+    }
+
     /// Gets underlying UUID object.
     #[inline]
     pub fn uuid(&self) -> Uuid {
         self.id
     }
+
+    /// Gets timestamp embedded in time-based (v1, v6 and v7) identifier.
+    /// Returns `None` for identifiers that do not carry a timestamp.
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let timestamp = self.id.get_timestamp()?;
+        // Gregorian (v1 and v6) timestamps before Unix epoch would wrap on conversion.
+        if matches!(self.id.get_version(), Some(Version::Mac | Version::SortMac))
+            && timestamp.to_gregorian().0 < UUID_TICKS_BETWEEN_EPOCHS
+        {
+            return None;
+        }
+        let (seconds, nanos) = timestamp.to_unix();
+        chrono::DateTime::from_timestamp(i64::try_from(seconds).ok()?, nanos)
+    }
+
+    /// Converts identifier into protobuf `bytes` field value.
+    #[cfg(feature = "prost")]
+    #[inline]
+    pub fn to_prost_bytes(&self) -> bytes::Bytes {
+        bytes::Bytes::copy_from_slice(self.id.as_bytes())
+    }
+
+    /// Creates new identifier from protobuf `bytes` field value, which must be exactly 16 bytes long.
+    #[cfg(feature = "prost")]
+    pub fn from_prost_bytes(b: &bytes::Bytes) -> Result<Self, String> {
+        match <[u8; 16]>::try_from(b.as_ref()) {
+            Ok(bytes) => Ok(Self::from_bytes(bytes)),
+            Err(_) => Err(format!("expected 16 bytes, found {}", b.len())),
+        }
+    }
+
+    /// Gets source location where this identifier was created with [`ID::new`].
+    /// Always `None` in release builds or for identifiers made from existing data.
+    /// See [`ID`] for how this affects layout.
+    #[cfg(feature = "debug_origin")]
+    #[inline]
+    pub fn origin(&self) -> Option<&'static Location<'static>> {
+        #[cfg(debug_assertions)]
+        {
+            self.origin
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            None
+        }
+    }
 }
 
 impl<T> Default for ID<T> {
     #[inline]
+    #[track_caller]
     fn default() -> Self {
         Self {
             id: Uuid::new_v4(),
+            #[cfg(all(feature = "debug_origin", debug_assertions))]
+            origin: Some(Location::caller()),
             _phantom: PhantomData,
         }
     }
 }
 
+static DEBUG_STYLE: AtomicU8 = AtomicU8::new(DebugStyle::Typed as u8);
+
+/// Style of identifier `Debug` output.
+///
+/// Defaults to [`DebugStyle::Typed`], which is a visible change from earlier versions that
+/// printed bare UUID - use [`DebugStyle::Plain`] to bring that output back.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum DebugStyle {
+    /// Bare UUID: `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Plain,
+    /// UUID wrapped with full type path of identifier:
+    /// `ID<path::Foo>(67e55044-10b1-426f-9247-bb680e5fe0c8)`.
+    #[default]
+    Typed,
+    /// First 8 hex digits of UUID: `67e55044`.
+    Short,
+}
+
+/// Sets process-wide style of identifier `Debug` output.
+///
+/// This setting is meant only for diagnostics - it is applied on best-effort basis and any
+/// thread can change it at any time.
+#[inline]
+pub fn set_debug_style(style: DebugStyle) {
+    DEBUG_STYLE.store(style as u8, atomic::Ordering::Relaxed);
+}
+
+/// Gets process-wide style of identifier `Debug` output.
+#[inline]
+pub fn debug_style() -> DebugStyle {
+    match DEBUG_STYLE.load(atomic::Ordering::Relaxed) {
+        0 => DebugStyle::Plain,
+        2 => DebugStyle::Short,
+        _ => DebugStyle::Typed,
+    }
+}
+
 impl<T> fmt::Debug for ID<T> {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_string())
+        match debug_style() {
+            DebugStyle::Plain => write!(f, "{}", self),
+            DebugStyle::Typed => write!(f, "ID<{}>({})", type_name::<T>(), self),
+            DebugStyle::Short => write!(f, "{:08x}", self.id.as_u128() >> 96),
+        }
     }
 }
 
-impl<T> ToString for ID<T> {
+impl<T> fmt::Display for ID<T> {
     #[inline]
-    fn to_string(&self) -> String {
-        format!("{}", self.id)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
+
+struct DisplayList<'a, T>(&'a [ID<T>]);
+
+impl<T> fmt::Display for DisplayList<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, id) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", id)?;
+        }
+        Ok(())
     }
 }
 
@@ -92,6 +287,8 @@ impl<T> FromStr for ID<T> {
         match Uuid::parse_str(s) {
             Ok(uuid) => Ok(Self {
                 id: uuid,
+                #[cfg(all(feature = "debug_origin", debug_assertions))]
+                origin: None,
                 _phantom: PhantomData,
             }),
             Err(_) => Err(s.to_owned()),
@@ -123,7 +320,7 @@ impl<T> Copy for ID<T> {}
 impl<T> PartialOrd for ID<T> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.id.cmp(&other.id))
+        Some(self.cmp(other))
     }
 }
 
@@ -136,10 +333,7 @@ impl<T> Ord for ID<T> {
 
 impl<T> Clone for ID<T> {
     fn clone(&self) -> Self {
-        Self {
-            id: self.id,
-            _phantom: PhantomData,
-        }
+        *self
     }
 }
 
@@ -158,6 +352,133 @@ impl<T> Into<IDDef> for ID<T> {
     }
 }
 
+impl<T> From<[u8; 16]> for ID<T> {
+    #[inline]
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<T> From<u128> for ID<T> {
+    #[inline]
+    fn from(value: u128) -> Self {
+        Self::from_bytes(value.to_be_bytes())
+    }
+}
+
+impl<T> From<ID<T>> for [u8; 16] {
+    #[inline]
+    fn from(id: ID<T>) -> Self {
+        *id.id.as_bytes()
+    }
+}
+
+impl<T> From<ID<T>> for u128 {
+    #[inline]
+    fn from(id: ID<T>) -> Self {
+        id.id.as_u128()
+    }
+}
+
+/// Serde `with` module storing identifier in `{"type", "id"}` envelope, where `type` is name
+/// of identifier's type. Deserialization fails if envelope is tagged with other type name.
+///
+/// Type name comes from [`std::any::type_name`], which is not stable: it may change between
+/// compiler versions and whenever the type is renamed or moved to other module. Data written
+/// by one build may then fail to load in another, so use this envelope only for short-lived
+/// or same-build data, never for long-term storage.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typid::ID;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Foo {
+///     #[serde(with = "typid::checked_tag")]
+///     pub id: ID<Foo>,
+/// }
+/// ```
+pub mod checked_tag {
+    use super::ID;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::{any::type_name, str::FromStr};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TaggedIDDef {
+        #[serde(rename = "type")]
+        type_name: String,
+        id: String,
+    }
+
+    pub fn serialize<T, S>(id: &ID<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        TaggedIDDef {
+            type_name: type_name::<T>().to_owned(),
+            id: id.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ID<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let tagged = TaggedIDDef::deserialize(deserializer)?;
+        if tagged.type_name != type_name::<T>() {
+            return Err(D::Error::custom(format!(
+                "expected ID of type `{}`, found `{}`",
+                type_name::<T>(),
+                tagged.type_name
+            )));
+        }
+        ID::from_str(&tagged.id).map_err(D::Error::custom)
+    }
+}
+
+/// Serde `with` module storing identifier as `{"uuid": "..."}` object.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use typid::ID;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Foo {
+///     #[serde(with = "typid::serde_nested")]
+///     pub id: ID<Foo>,
+/// }
+/// ```
+pub mod serde_nested {
+    use super::ID;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::str::FromStr;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct NestedIDDef {
+        uuid: String,
+    }
+
+    pub fn serialize<T, S>(id: &ID<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        NestedIDDef {
+            uuid: id.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ID<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let nested = NestedIDDef::deserialize(deserializer)?;
+        ID::from_str(&nested.uuid).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +493,244 @@ mod tests {
         let b = Foo { id: ID::new() };
         assert_ne!(a.id, b.id);
     }
+
+    #[test]
+    fn test_new_batch_v7() {
+        let ids = ID::<()>::new_batch_v7(10_000);
+        assert_eq!(ids.len(), 10_000);
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ids.iter().all(|id| id.uuid().get_version_num() == 7));
+
+        for _ in 0..1000 {
+            let mut ids = ID::<()>::new_batch_v7(3);
+            ids.extend(ID::<()>::new_batch_v7(3));
+            assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_checked_from_bytes() {
+        let bytes = [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ];
+        let id = ID::<()>::checked_from_bytes(bytes).unwrap();
+        assert_eq!(id.uuid().as_bytes(), &bytes);
+
+        assert!(ID::<()>::checked_from_bytes([0xff; 16]).is_err());
+        assert!(ID::<()>::checked_from_bytes([0; 16]).is_err());
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let a = ID::<()>::new();
+        let b = ID::<()>::new();
+        let c = ID::<()>::new();
+
+        let text = format!("{}\n{}\r\n\n{}\n", a, b, c);
+        assert_eq!(ID::<()>::parse_many(&text).unwrap(), vec![a, b, c]);
+
+        let text = format!("{}, {},{}", a, b, c);
+        assert_eq!(ID::<()>::parse_many(&text).unwrap(), vec![a, b, c]);
+
+        let text = format!("{},,{}, foo ,{}", a, b, c);
+        assert_eq!(
+            ID::<()>::parse_many(&text).unwrap_err(),
+            (2, "foo".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_reader_writer() {
+        let a = ID::<()>::new();
+        let b = ID::<()>::new();
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        a.write_to(&mut cursor).unwrap();
+        b.write_to(&mut cursor).unwrap();
+        assert_eq!(cursor.get_ref().len(), 32);
+
+        cursor.set_position(0);
+        assert_eq!(ID::<()>::from_reader(&mut cursor).unwrap(), a);
+        assert_eq!(ID::<()>::from_reader(&mut cursor).unwrap(), b);
+
+        let mut cursor = std::io::Cursor::new(vec![0; 15]);
+        let error = ID::<()>::from_reader(&mut cursor).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_debug_style() {
+        struct Foo;
+
+        let id = ID::<Foo>::from(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8_u128);
+        assert_eq!(DebugStyle::default(), DebugStyle::Typed);
+
+        // Style is process-wide, so all variants are checked in single test.
+        set_debug_style(DebugStyle::Plain);
+        assert_eq!(debug_style(), DebugStyle::Plain);
+        assert_eq!(format!("{:?}", id), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+        set_debug_style(DebugStyle::Short);
+        assert_eq!(debug_style(), DebugStyle::Short);
+        assert_eq!(format!("{:?}", id), "67e55044");
+
+        set_debug_style(DebugStyle::Typed);
+        assert_eq!(debug_style(), DebugStyle::Typed);
+        assert_eq!(
+            format!("{:?}", id),
+            format!(
+                "ID<{}>(67e55044-10b1-426f-9247-bb680e5fe0c8)",
+                std::any::type_name::<Foo>()
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_list() {
+        let ids = [
+            ID::<()>::from(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8_u128),
+            ID::<()>::from(0),
+            ID::<()>::from(u128::MAX),
+        ];
+        assert_eq!(
+            ID::display_list(&ids).to_string(),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8, \
+            00000000-0000-0000-0000-000000000000, \
+            ffffffff-ffff-ffff-ffff-ffffffffffff"
+        );
+        assert_eq!(ID::<()>::display_list(&[]).to_string(), "");
+    }
+
+    #[test]
+    fn test_checked_tag() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Foo {
+            #[serde(with = "checked_tag")]
+            pub id: ID<Foo>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Bar {
+            #[serde(with = "checked_tag")]
+            pub id: ID<Bar>,
+        }
+
+        let foo = Foo { id: ID::new() };
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"id":{{"type":"{}","id":"{}"}}}}"#,
+                std::any::type_name::<Foo>(),
+                foo.id
+            )
+        );
+        assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), foo);
+        assert!(serde_json::from_str::<Bar>(&json).is_err());
+    }
+
+    #[test]
+    fn test_from_into() {
+        let bytes = [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ];
+        let id: ID<()> = bytes.into();
+        assert_eq!(id.uuid(), Uuid::from_bytes(bytes));
+        let result: [u8; 16] = id.into();
+        assert_eq!(result, bytes);
+
+        let value = 0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8_u128;
+        let id: ID<()> = value.into();
+        assert_eq!(id.uuid(), Uuid::from_u128(value));
+        let result: u128 = id.into();
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn test_serde_nested() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Foo {
+            #[serde(with = "serde_nested")]
+            pub id: ID<Foo>,
+        }
+
+        let foo = Foo { id: ID::new() };
+        let json = serde_json::to_string(&foo).unwrap();
+        assert_eq!(json, format!(r#"{{"id":{{"uuid":"{}"}}}}"#, foo.id));
+        assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), foo);
+
+        let error = serde_json::from_str::<Foo>(r#"{"id":{}}"#).unwrap_err();
+        assert!(error.to_string().contains("missing field `uuid`"));
+        let json = format!(r#"{{"id":{{"uuid":"{}","extra":0}}}}"#, foo.id);
+        let error = serde_json::from_str::<Foo>(&json).unwrap_err();
+        assert!(error.to_string().contains("unknown field `extra`"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetime() {
+        let id = ID::<()>::from_bytes(*Uuid::now_v7().as_bytes());
+        let delta = chrono::Utc::now() - id.datetime().unwrap();
+        assert!(delta.num_milliseconds().abs() < 1000);
+
+        let id = ID::<()>::from_str("1d3bfde6-46c6-6146-8000-000000000000").unwrap();
+        assert_eq!(
+            id.datetime().unwrap().to_rfc3339(),
+            "2000-01-01T00:00:01.234567+00:00"
+        );
+
+        // 1900-01-01, before Unix epoch.
+        let id = ID::<()>::from_str("3c230000-a32f-1163-8000-000000000000").unwrap();
+        assert!(id.datetime().is_none());
+
+        assert!(ID::<()>::new().datetime().is_none());
+        assert!(ID::<()>::from_bytes([0; 16]).datetime().is_none());
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_prost_bytes() {
+        let id = ID::<()>::new();
+        let b = id.to_prost_bytes();
+        assert_eq!(b.len(), 16);
+        assert_eq!(ID::<()>::from_prost_bytes(&b).unwrap(), id);
+
+        let b = bytes::Bytes::from_static(&[0; 15]);
+        assert!(ID::<()>::from_prost_bytes(&b).is_err());
+    }
+
+    #[cfg(all(feature = "debug_origin", debug_assertions))]
+    #[test]
+    fn test_debug_origin() {
+        let id = ID::<()>::new();
+        let line = line!() - 1;
+        let origin = id.origin().unwrap();
+        assert_eq!(origin.file(), file!());
+        assert_eq!(origin.line(), line);
+        assert!(ID::<()>::from_bytes([0; 16]).origin().is_none());
+    }
+
+    #[cfg(not(all(feature = "debug_origin", debug_assertions)))]
+    #[test]
+    fn test_layout() {
+        struct Foo;
+
+        assert_eq!(ID::<Foo>::SIZE, 16);
+        assert_eq!(std::mem::size_of::<ID<Foo>>(), ID::<Foo>::SIZE);
+        assert_eq!(std::mem::align_of::<ID<Foo>>(), 1);
+    }
+
+    #[cfg(all(
+        feature = "debug_origin",
+        debug_assertions,
+        target_pointer_width = "64"
+    ))]
+    #[test]
+    fn test_debug_origin_layout() {
+        struct Foo;
+
+        assert_eq!(std::mem::size_of::<ID<Foo>>(), 24);
+        assert_eq!(std::mem::align_of::<ID<Foo>>(), 8);
+    }
 }